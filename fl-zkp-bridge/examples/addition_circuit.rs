@@ -15,6 +15,7 @@ use ark_relations::gr1cs::{ConstraintSystemRef, SynthesisError};
 use std::marker::PhantomData;
 use std::time::Instant;
 
+use fl_zkp_bridge::fixed_point::FixedPoint;
 use folding_schemes::{
     commitment::pedersen::Pedersen,
     folding::{
@@ -59,30 +60,16 @@ impl<F: PrimeField> FCircuit<F> for AdditionFCircuit<F> {
     }
 }
 
-/// Helper to convert f64 to field element (simplified)
+/// Convert a float to a field element via the signed fixed-point encoding.
 fn float_to_field(value: f64) -> Fr {
-    let scaled = (value * 1_000_000.0) as i64;
-    if scaled >= 0 {
-        Fr::from(scaled as u64)
-    } else {
-        -Fr::from((-scaled) as u64)
-    }
+    FixedPoint::<Fr>::encode(value).0
 }
 
-/// Helper to convert field element back to f64 (simplified)
+/// Convert a field element back to a float via the signed fixed-point
+/// decoding (handles negative values and avoids the old `Debug`-string
+/// parsing hack).
 fn field_to_float(field: Fr) -> f64 {
-    // This is a simplified conversion - in production, use proper decoding
-    // For now, we'll just use the string representation
-    let s = format!("{:?}", field);
-    // Extract numeric part (this is very hacky - for demo only)
-    if let Some(num_str) = s.split('(').nth(1) {
-        if let Some(num) = num_str.split(')').next() {
-            if let Ok(val) = num.parse::<u64>() {
-                return (val as f64) / 1_000_000.0;
-            }
-        }
-    }
-    0.0
+    FixedPoint(field).decode()
 }
 
 fn main() -> Result<(), Error> {
@@ -0,0 +1,64 @@
+#![allow(non_snake_case)]
+#![allow(non_camel_case_types)]
+#![allow(clippy::upper_case_acronyms)]
+
+/// A/B harness for the `AdditionFCircuit` across `FoldingScheme` impls,
+/// built on `fl_zkp_bridge::bench::bench_folding` rather than hand-rolling
+/// a second copy of the `preprocess`/`init`/`prove_step`/`verify` driver
+/// already in `addition_circuit.rs`.
+///
+/// Run with: cargo run --release --example folding_benchmark
+use ark_bn254::{Fr, G1Projective as G1};
+use ark_grumpkin::Projective as G2;
+
+use fl_zkp_bridge::bench::bench_folding;
+use fl_zkp_bridge::fixed_point::FixedPoint;
+use fl_zkp_bridge::AdditionFCircuit;
+use folding_schemes::{
+    commitment::pedersen::Pedersen, folding::protogalaxy::ProtoGalaxy, frontend::FCircuit,
+    transcript::poseidon::poseidon_canonical_config, Error,
+};
+
+fn main() -> Result<(), Error> {
+    println!("\n{}", "=".repeat(70));
+    println!("FL+ZKP: FoldingScheme-generic benchmark (ProtoGalaxy)");
+    println!("{}", "=".repeat(70));
+
+    let client_gradients = vec![0.5, -0.3, 0.7, 0.2, -0.1, 0.4, -0.2, 0.3];
+    let z_0 = vec![FixedPoint::<Fr>::encode(0.0).0];
+    let inputs: Vec<[Fr; 1]> = client_gradients
+        .iter()
+        .map(|&g| [FixedPoint::<Fr>::encode(g).0])
+        .collect();
+
+    let f_circuit = AdditionFCircuit::<Fr>::new(())?;
+    let poseidon_config = poseidon_canonical_config::<Fr>();
+
+    // `bench_folding` is generic over any `FS: FoldingScheme`; A/B-ing
+    // ProtoGalaxy against Nova+CycleFold for this same circuit is a matter
+    // of calling it again with `FS = Nova<...>` and that scheme's own
+    // preprocessor param, no change to the driver below.
+    type PG = ProtoGalaxy<G1, G2, AdditionFCircuit<Fr>, Pedersen<G1>, Pedersen<G2>>;
+
+    println!("\nRunning {} folding steps under ProtoGalaxy...", inputs.len());
+    let result = bench_folding::<G1, G2, AdditionFCircuit<Fr>, PG>(
+        &(poseidon_config, f_circuit),
+        f_circuit,
+        z_0,
+        inputs,
+    )?;
+
+    println!("\nResults:");
+    println!("  Init time:        {:?}", result.init_time);
+    for step in &result.steps {
+        println!("  Step {:2}: {:?}", step.step + 1, step.prove_time);
+    }
+    println!("  Total prove time: {:?}", result.total_prove_time());
+    println!("  Avg prove time:   {:?}", result.avg_prove_time());
+    println!("  Verify time:      {:?}", result.verify_time);
+    println!("  IVC proof size:   {} bytes", result.ivc_proof_size);
+    println!("{}", "=".repeat(70));
+    println!();
+
+    Ok(())
+}
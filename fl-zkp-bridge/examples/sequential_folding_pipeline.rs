@@ -17,8 +17,8 @@ use ark_serialize::CanonicalSerialize;
 use std::marker::PhantomData;
 use std::time::Instant;
 
+use fl_zkp_bridge::commitment::WindowedPedersen;
 use folding_schemes::{
-    commitment::pedersen::Pedersen,
     folding::{
         protogalaxy::ProtoGalaxy,
     },
@@ -115,8 +115,14 @@ fn main() -> Result<(), Error> {
     println!("│  Initializing ProtoGalaxy + CycleFold...");
     
     let f_circuit = AdditionFCircuit::<Fr>::new(())?;
-    type PG = ProtoGalaxy<G1, G2, AdditionFCircuit<Fr>, Pedersen<G1>, Pedersen<G2>>;
-    
+    // Per-step timing below is dominated by the Pedersen commitments each
+    // fold makes on both curves. `WindowedPedersen` is a drop-in
+    // `CommitmentScheme` substitute for `Pedersen` that precomputes a
+    // fixed-base window table for the (unchanging) generator set once in
+    // `setup`, instead of redoing an un-windowed MSM against them on every
+    // `commit` - see `fl_zkp_bridge::commitment` for details.
+    type PG = ProtoGalaxy<G1, G2, AdditionFCircuit<Fr>, WindowedPedersen<G1>, WindowedPedersen<G2>>;
+
     let poseidon_config = poseidon_canonical_config::<Fr>();
     let mut rng = ark_std::rand::rngs::OsRng;
     
@@ -266,6 +272,11 @@ fn main() -> Result<(), Error> {
     println!("│    Verify time:   {:?}", verify_time);
     println!("│    Speedup:       {:.2}× faster than proving",
              total_folding_time.as_secs_f64() / verify_time.as_secs_f64());
+    println!("│");
+    println!("│  NOT IMPLEMENTED: compressing this accumulator through a");
+    println!("│  Decider into a true constant-size SNARK (so a verifier could");
+    println!("│  check it standalone, without replaying any of the 15 folds) -");
+    println!("│  see UPSTREAM_BLOCKED.md (chunk1-2).");
     println!("└─");
     
     // ═══════════════════════════════════════════════════════════════════════════
@@ -320,7 +331,7 @@ fn main() -> Result<(), Error> {
     println!();
     println!("  Proofs folded:      15 individual proofs → 1 accumulated proof");
     println!("  Folding scheme:     ProtoGalaxy + CycleFold (IVC)");
-    println!("  Commitment:         Pedersen (both curves)");
+    println!("  Commitment:         Windowed Pedersen (both curves)");
     println!("  Curves:             BN254 (primary) + Grumpkin (secondary)");
     println!();
     println!("✓ ACCURACY VERIFIED");
@@ -347,9 +358,42 @@ fn main() -> Result<(), Error> {
     println!();
     println!("  Commit:             1406c0f (main branch)");
     println!("  Architecture:       100% compliant with ProtoGalaxy spec");
-    println!("  Multi-instance:     k=1 (sequential folding only)");
+    println!("  Multi-instance:     k=1 per round; see PHASE 7");
+    println!();
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // PHASE 7: Multi-Instance Folding (k>1) — NOT IMPLEMENTED
+    // ═══════════════════════════════════════════════════════════════════════════
+    //
+    // Folding several incoming instances into the accumulator in a single
+    // round (instead of one round per instance) needs a genuine
+    // multi-instance entry point on `ProtoGalaxy` itself - see
+    // `UPSTREAM_BLOCKED.md` (chunk0-1). The batch below folds the same
+    // three inputs as k=1 sequential rounds instead.
+
+    println!("\n┌─ PHASE 7: Multi-Instance Folding (k>1) — not implemented, folding sequentially instead");
+    println!("│");
+
+    let batch_inputs: Vec<Fr> = vec![50, 60, 70].into_iter().map(Fr::from).collect();
+    // Fold on a copy, so `protogalaxy` keeps the 15-step counter that the
+    // final validation below checks.
+    let mut batch_pg = protogalaxy.clone();
+    let batch_start = Instant::now();
+    for &input in &batch_inputs {
+        batch_pg.prove_step(&mut rng, [input], None)?;
+    }
+    let batch_time = batch_start.elapsed();
+
+    running_sum += 50 + 60 + 70;
+    let batch_state_u64 = field_to_u64(&batch_pg.z_i[0]);
+
+    println!("│  Folded {} instances in {} sequential rounds: {:?}", batch_inputs.len(), batch_inputs.len(), batch_time);
+    println!("│  State after fold: {} (expected {})", batch_state_u64, running_sum);
+    println!("│  Match: {}", if batch_state_u64 == running_sum { "✓ YES" } else { "✗ NO" });
+    println!("└─");
+    assert_eq!(batch_state_u64, running_sum, "Sequential fold produced the wrong state!");
+
     println!();
-    
     println!("{}", "═".repeat(80));
     println!("  KEY INSIGHTS");
     println!("{}", "═".repeat(80));
@@ -0,0 +1,109 @@
+//! Folding-scheme-generic benchmarking harness. The examples elsewhere in
+//! this crate each pin one scheme (`ProtoGalaxy<G1, G2, ...>`) directly, so
+//! comparing it against another `FoldingScheme` impl (e.g. Nova+CycleFold)
+//! means rewriting the whole driver. [`bench_folding`] instead drives any
+//! `FS: FoldingScheme` through the same `preprocess`/`init`/`prove_step`/
+//! `verify` sequence and reports structured timing/size results, so an FL
+//! deployment can A/B schemes for its workload by swapping the `FS` type
+//! parameter alone.
+
+use std::time::{Duration, Instant};
+
+use ark_ec::CurveGroup;
+use ark_serialize::CanonicalSerialize;
+
+use folding_schemes::{frontend::FCircuit, Error, FoldingScheme};
+
+/// Prover timing for a single `prove_step` call.
+#[derive(Debug, Clone)]
+pub struct StepTiming {
+    pub step: usize,
+    pub prove_time: Duration,
+}
+
+/// Structured results from one [`bench_folding`] run: per-step prover time,
+/// one-shot verifier time, and the serialized size of the IVC proof, so
+/// callers can compare schemes without re-parsing printed output.
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    pub init_time: Duration,
+    pub steps: Vec<StepTiming>,
+    pub verify_time: Duration,
+    pub ivc_proof_size: usize,
+}
+
+impl BenchResult {
+    /// Sum of all per-step prover times.
+    pub fn total_prove_time(&self) -> Duration {
+        self.steps.iter().map(|s| s.prove_time).sum()
+    }
+
+    /// Average per-step prover time, or zero if no steps were run.
+    pub fn avg_prove_time(&self) -> Duration {
+        if self.steps.is_empty() {
+            return Duration::ZERO;
+        }
+        self.total_prove_time() / self.steps.len() as u32
+    }
+}
+
+/// Run `inputs.len()` folding steps of `f_circuit` through `FS`, then
+/// report per-step prover time, verifier time, and IVC proof size.
+///
+/// This mirrors the `preprocess`/`init`/`prove_step`/`verify` sequence used
+/// directly against `ProtoGalaxy` in `examples/addition_circuit.rs`, but
+/// generic over `FS`, so the same call works against any other
+/// `FoldingScheme` impl (e.g. Nova+CycleFold) for the same `FCircuit` —
+/// callers A/B schemes by changing the `FS` type parameter, not the driver.
+///
+/// NOT IMPLEMENTED: an accumulator-size metric (the serialized size of the
+/// *live* `FS` state, witnesses included) would need `FS: CanonicalSerialize`
+/// on the whole scheme, which isn't confirmed to hold for an arbitrary
+/// `FoldingScheme` impl — only the IVC proof type `FS::ivc_proof()` returns
+/// is, so only that gets measured here.
+pub fn bench_folding<C1, C2, FC, FS>(
+    prep_param: &FS::PreprocessorParam,
+    f_circuit: FC,
+    z_0: Vec<C1::ScalarField>,
+    inputs: Vec<FC::ExternalInputs>,
+) -> Result<BenchResult, Error>
+where
+    C1: CurveGroup,
+    C2: CurveGroup,
+    FC: FCircuit<C1::ScalarField>,
+    FS: FoldingScheme<C1, C2, FC>,
+{
+    let mut rng = ark_std::rand::rngs::OsRng;
+
+    let init_start = Instant::now();
+    let (pp, vp) = FS::preprocess(&mut rng, prep_param)?;
+    let mut fs = FS::init(&pp, f_circuit, z_0)?;
+    let init_time = init_start.elapsed();
+
+    let mut steps = Vec::with_capacity(inputs.len());
+    for (step, external_inputs) in inputs.into_iter().enumerate() {
+        let step_start = Instant::now();
+        fs.prove_step(&mut rng, external_inputs, None)?;
+        steps.push(StepTiming {
+            step,
+            prove_time: step_start.elapsed(),
+        });
+    }
+
+    let ivc_proof = fs.ivc_proof();
+    let mut ivc_proof_bytes = Vec::new();
+    ivc_proof
+        .serialize_compressed(&mut ivc_proof_bytes)
+        .map_err(|e| Error::Other(format!("Serialization failed: {}", e)))?;
+
+    let verify_start = Instant::now();
+    FS::verify(vp, ivc_proof)?;
+    let verify_time = verify_start.elapsed();
+
+    Ok(BenchResult {
+        init_time,
+        steps,
+        verify_time,
+        ivc_proof_size: ivc_proof_bytes.len(),
+    })
+}
@@ -0,0 +1,154 @@
+//! Fixed-base windowed Pedersen commitments.
+//!
+//! `folding_schemes::commitment::pedersen::Pedersen<C>` is a
+//! `CommitmentScheme<C>` impl whose `commit` recomputes its multi-scalar
+//! multiplication against the same fixed generator set on every call - the
+//! generators never change across a folding run, but nothing caches a
+//! windowed table for them. [`WindowedPedersen`] is a drop-in substitute:
+//! it reuses `Pedersen<C>`'s own params, proof type, `prove`, and `verify`
+//! unchanged (so a verifier built against plain `Pedersen<C>` still accepts
+//! its commitments), and only overrides `commit` to multiply through a
+//! [`FixedBase`] window table built once in `setup` instead of an
+//! un-windowed MSM.
+//!
+//! `ProtoGalaxy<G1, G2, FC, CS1, CS2>` takes the commitment scheme as a
+//! type parameter, so swapping this in for `Pedersen<G1>`/`Pedersen<G2>`
+//! needs no change to `folding_schemes` itself.
+
+use std::marker::PhantomData;
+
+use ark_ec::{scalar_mul::fixed_base::FixedBase, CurveGroup};
+use ark_ff::PrimeField;
+use ark_std::rand::RngCore;
+
+use folding_schemes::{
+    commitment::{pedersen::Pedersen, CommitmentScheme},
+    transcript::Transcript,
+    Error,
+};
+
+/// `Pedersen<C, H>` with a precomputed fixed-base window table for `commit`.
+#[derive(Clone, Debug)]
+pub struct WindowedPedersen<C: CurveGroup, const H: bool = false> {
+    _c: PhantomData<C>,
+}
+
+/// Window size for the fixed-base table, as `ark-ec`'s own
+/// `FixedBase::get_mul_window_size` picks it from the number of scalars to
+/// be multiplied; the scalar field's size does not enter into it.
+fn window_size(num_scalars: usize) -> usize {
+    FixedBase::get_mul_window_size(num_scalars)
+}
+
+impl<C: CurveGroup, const H: bool> CommitmentScheme<C, H> for WindowedPedersen<C, H> {
+    type ProverParams = (
+        <Pedersen<C, H> as CommitmentScheme<C, H>>::ProverParams,
+        Vec<Vec<C>>,
+    );
+    type VerifierParams = <Pedersen<C, H> as CommitmentScheme<C, H>>::VerifierParams;
+    type Proof = <Pedersen<C, H> as CommitmentScheme<C, H>>::Proof;
+    type ProverChallenge = <Pedersen<C, H> as CommitmentScheme<C, H>>::ProverChallenge;
+    type Challenge = <Pedersen<C, H> as CommitmentScheme<C, H>>::Challenge;
+
+    fn is_hiding() -> bool {
+        Pedersen::<C, H>::is_hiding()
+    }
+
+    fn setup(
+        rng: impl RngCore,
+        len: usize,
+    ) -> Result<(Self::ProverParams, Self::VerifierParams), Error> {
+        let (pp, vp) = Pedersen::<C, H>::setup(rng, len)?;
+
+        let scalar_bits = C::ScalarField::MODULUS_BIT_SIZE as usize;
+        let window = window_size(pp.generators.len() + 1);
+        let tables = pp
+            .generators
+            .iter()
+            .map(|g| FixedBase::get_window_table(scalar_bits, window, g.into_group()))
+            .collect();
+
+        Ok(((pp, tables), vp))
+    }
+
+    fn commit(
+        params: &Self::ProverParams,
+        v: &[C::ScalarField],
+        blind: &C::ScalarField,
+    ) -> Result<C, Error> {
+        let (pp, tables) = params;
+        if v.len() > tables.len() {
+            return Err(Error::Other(format!(
+                "WindowedPedersen: {} values but only {} precomputed generators",
+                v.len(),
+                tables.len()
+            )));
+        }
+
+        let scalar_bits = C::ScalarField::MODULUS_BIT_SIZE as usize;
+        let window = window_size(tables.len() + 1);
+        let outerc = (scalar_bits + window - 1) / window;
+        let mut commitment = v
+            .iter()
+            .zip(tables.iter())
+            .map(|(scalar, table)| FixedBase::windowed_mul(outerc, window, table, scalar))
+            .sum::<C>();
+
+        if Self::is_hiding() {
+            commitment += pp.h.mul_bigint(blind.into_bigint());
+        }
+
+        Ok(commitment)
+    }
+
+    fn prove(
+        params: &Self::ProverParams,
+        transcript: &mut impl Transcript<C::ScalarField>,
+        cm: &C,
+        v: &[C::ScalarField],
+        blind: &C::ScalarField,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<Self::Proof, Error> {
+        let (pp, _tables) = params;
+        Pedersen::<C, H>::prove(pp, transcript, cm, v, blind, rng)
+    }
+
+    fn verify(
+        params: &Self::VerifierParams,
+        transcript: &mut impl Transcript<C::ScalarField>,
+        cm: &C,
+        proof: &Self::Proof,
+    ) -> Result<(), Error> {
+        Pedersen::<C, H>::verify(params, transcript, cm, proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fr, G1Projective as G1};
+    use ark_std::{rand::rngs::OsRng, UniformRand};
+
+    /// The module doc's entire premise is that `WindowedPedersen::commit`
+    /// agrees with `Pedersen::commit` for the same params/scalars/blinding
+    /// - otherwise a verifier built against plain `Pedersen` would reject
+    /// its commitments. Check that directly instead of just asserting it
+    /// in prose.
+    #[test]
+    fn commit_matches_plain_pedersen() {
+        let mut rng = OsRng;
+        let len = 5;
+
+        let ((pedersen_pp, tables), _vp) =
+            WindowedPedersen::<G1>::setup(&mut rng, len).unwrap();
+
+        let v: Vec<Fr> = (0..len).map(|_| Fr::rand(&mut rng)).collect();
+        let blind = Fr::rand(&mut rng);
+
+        let windowed = WindowedPedersen::<G1>::commit(&(pedersen_pp.clone(), tables), &v, &blind)
+            .unwrap();
+        let plain = Pedersen::<G1>::commit(&pedersen_pp, &v, &blind).unwrap();
+
+        assert_eq!(windowed, plain);
+    }
+}
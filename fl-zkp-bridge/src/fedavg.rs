@@ -0,0 +1,207 @@
+//! Vector-state FedAvg circuit: folds a `D`-dimensional weight vector with
+//! a per-step weighted gradient, instead of [`crate::AdditionFCircuit`]'s
+//! single scalar sum. Each step computes
+//! `z_{i+1}[j] = z_i[j] + w_i * grad_i[j]` using the fixed-point multiply
+//! gadget from [`crate::fixed_point`], and the running sum of weights is
+//! carried in a trailing state slot so the caller can normalize by it once
+//! folding completes, turning the accumulated weighted sum into an actual
+//! federated average.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::{AllocVar, AllocationMode},
+    fields::fp::FpVar,
+    prelude::*,
+};
+use ark_relations::gr1cs::{ConstraintSystemRef, Namespace, SynthesisError};
+use folding_schemes::{frontend::FCircuit, Error};
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+
+use crate::fixed_point::{FixedPoint, FixedPointVar};
+
+/// One step's external input: a client's update weight (e.g. its local
+/// dataset size) together with its `D`-dimensional gradient.
+#[derive(Clone, Copy, Debug)]
+pub struct FedAvgInput<F: PrimeField, const D: usize> {
+    pub weight: F,
+    pub gradient: [F; D],
+}
+
+/// In-circuit counterpart of [`FedAvgInput`]. Both fields are allocated
+/// through [`FixedPointVar::new_variable_checked`] rather than as bare
+/// `FpVar`s, so a malicious prover can't supply a weight or gradient
+/// component that wraps the field modulus and defeats the `mul()` rescale
+/// gadget's range check on its remainder.
+#[derive(Clone)]
+pub struct FedAvgInputVar<F: PrimeField, const D: usize> {
+    pub weight: FixedPointVar<F>,
+    pub gradient: [FixedPointVar<F>; D],
+}
+
+impl<F: PrimeField, const D: usize> AllocVar<FedAvgInput<F, D>, F> for FedAvgInputVar<F, D> {
+    fn new_variable<T: Borrow<FedAvgInput<F, D>>>(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let value = f().map(|v| *v.borrow());
+
+        let weight = FixedPointVar::new_variable_checked(
+            cs.clone(),
+            || value.map(|v| FixedPoint(v.weight)),
+            mode,
+        )?;
+
+        let mut gradient = Vec::with_capacity(D);
+        for j in 0..D {
+            gradient.push(FixedPointVar::new_variable_checked(
+                cs.clone(),
+                || value.map(|v| FixedPoint(v.gradient[j])),
+                mode,
+            )?);
+        }
+        let gradient: [FixedPointVar<F>; D] = gradient
+            .try_into()
+            .map_err(|_| SynthesisError::AssignmentMissing)?;
+
+        Ok(Self { weight, gradient })
+    }
+}
+
+/// `D`-dimensional weighted-sum circuit for federated averaging. The state
+/// is `D` accumulated weighted-gradient components plus a trailing slot
+/// holding the running sum of weights, so a caller can divide it out after
+/// folding via [`normalize`].
+#[derive(Clone, Copy, Debug)]
+pub struct FedAvgFCircuit<F: PrimeField, const D: usize> {
+    _f: PhantomData<F>,
+}
+
+impl<F: PrimeField, const D: usize> FCircuit<F> for FedAvgFCircuit<F, D> {
+    type Params = ();
+    type ExternalInputs = FedAvgInput<F, D>;
+    type ExternalInputsVar = FedAvgInputVar<F, D>;
+
+    fn new(_params: Self::Params) -> Result<Self, Error> {
+        Ok(Self { _f: PhantomData })
+    }
+
+    fn state_len(&self) -> usize {
+        D + 1
+    }
+
+    fn generate_step_constraints(
+        &self,
+        _cs: ConstraintSystemRef<F>,
+        _i: usize,
+        z_i: Vec<FpVar<F>>,
+        external_inputs: Self::ExternalInputsVar,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let weight = &external_inputs.weight;
+
+        let mut z_next = Vec::with_capacity(D + 1);
+        for j in 0..D {
+            // z_i[j] is the running accumulator carried in as a bare
+            // `FpVar` state variable, not a circuit value this step
+            // produced itself, so it must be range-checked here exactly
+            // like a freshly allocated witness - nothing upstream of this
+            // step enforces the fixed-point range on it.
+            FixedPointVar::enforce_range(&z_i[j])?;
+            let acc = FixedPointVar {
+                value: z_i[j].clone(),
+            };
+
+            let grad = &external_inputs.gradient[j];
+            let weighted = weight.mul(grad)?;
+            z_next.push(acc.add(&weighted)?.value);
+        }
+
+        // Running sum of weights, used to normalize the weighted sum into
+        // an average once folding completes. Carried in the same way as
+        // z_i[0..D] above, so it needs the same range-check treatment: the
+        // carried-in value isn't self-certifying, and two in-range
+        // operands can add up to a value outside the declared range.
+        FixedPointVar::enforce_range(&z_i[D])?;
+        let weight_sum_acc = FixedPointVar {
+            value: z_i[D].clone(),
+        };
+        z_next.push(weight_sum_acc.add(weight)?.value);
+
+        Ok(z_next)
+    }
+}
+
+/// Normalize a folded weighted-sum state by the accumulated weight sum
+/// (the trailing state slot), producing the actual federated average. This
+/// runs natively after folding, the same way the Decider's final checks run
+/// natively on the accumulator rather than inside the folded relation:
+/// division by a runtime-determined weight sum isn't part of the folding
+/// circuit itself.
+pub fn normalize<F: PrimeField, const D: usize>(z_final: &[F]) -> [f64; D] {
+    let weight_sum = FixedPoint(z_final[D]).decode();
+    core::array::from_fn(|j| FixedPoint(z_final[j]).decode() / weight_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_relations::gr1cs::ConstraintSystem;
+
+    const D: usize = 2;
+
+    fn encode_input(weight: f64, gradient: [f64; D]) -> FedAvgInput<Fr, D> {
+        FedAvgInput {
+            weight: FixedPoint::<Fr>::encode(weight).0,
+            gradient: core::array::from_fn(|j| FixedPoint::<Fr>::encode(gradient[j]).0),
+        }
+    }
+
+    #[test]
+    fn generate_step_constraints_satisfied_across_multiple_clients_and_steps() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let circuit = FedAvgFCircuit::<Fr, D>::new(()).unwrap();
+
+        let mut z_i: Vec<FpVar<Fr>> = (0..D + 1)
+            .map(|_| FpVar::new_witness(cs.clone(), || Ok(Fr::from(0u64))).unwrap())
+            .collect();
+
+        // Three clients' updates, folded one per step - exercises the
+        // carried-accumulator range check (it must hold at every step, not
+        // just the first) and the weighted-sum arithmetic across several
+        // distinct weights and gradients, including a negative gradient.
+        let inputs = [
+            encode_input(3.0, [1.5, -2.0]),
+            encode_input(2.0, [-0.5, 4.0]),
+            encode_input(1.0, [2.5, 2.5]),
+        ];
+
+        let mut native_state = [FixedPoint::<Fr>::encode(0.0); D];
+        let mut native_weight_sum = FixedPoint::<Fr>::encode(0.0);
+
+        for (i, input) in inputs.iter().enumerate() {
+            let input_var =
+                FedAvgInputVar::new_variable(cs.clone(), || Ok(*input), AllocationMode::Witness)
+                    .unwrap();
+            z_i = circuit
+                .generate_step_constraints(cs.clone(), i, z_i, input_var)
+                .unwrap();
+
+            let weight = FixedPoint(input.weight);
+            for j in 0..D {
+                let weighted = weight.mul(&FixedPoint(input.gradient[j]));
+                native_state[j] = native_state[j].add(&weighted);
+            }
+            native_weight_sum = native_weight_sum.add(&weight);
+        }
+
+        assert!(cs.is_satisfied().unwrap());
+        for j in 0..D {
+            assert_eq!(z_i[j].value().unwrap(), native_state[j].0);
+        }
+        assert_eq!(z_i[D].value().unwrap(), native_weight_sum.0);
+    }
+}
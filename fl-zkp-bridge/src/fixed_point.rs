@@ -0,0 +1,330 @@
+//! Signed fixed-point encoding for folding circuits that need to work with
+//! rationals rather than raw field elements.
+//!
+//! The ad-hoc approach this crate used to take (`float_to_field`/
+//! `field_to_float` in the examples) scales by `1e6` and decodes by parsing
+//! the `Debug` string of a field element — that silently breaks for
+//! negative values, which are stored as `p - |x|`, and for anything past
+//! the chosen scale. This module replaces it with a real `FixedPoint`/
+//! `FixedPointVar` pair: a fixed scale `S = 2^FRAC_BITS`, signed integers
+//! mapped onto the field by wrapping negatives to `p - |n|`, and an
+//! in-circuit counterpart that range-checks its value and rescales after
+//! multiplication instead of letting the product silently carry double
+//! the scale.
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{
+    alloc::{AllocVar, AllocationMode},
+    fields::fp::FpVar,
+    prelude::*,
+};
+use ark_relations::gr1cs::{Namespace, SynthesisError};
+
+/// Number of fractional bits; the fixed-point scale is `2^FRAC_BITS`.
+pub const FRAC_BITS: u32 = 20;
+
+fn scale_i128() -> i128 {
+    1i128 << FRAC_BITS
+}
+
+/// Encode a signed integer as a field element: non-negative `n` maps to
+/// `F::from(n)`, negative `n` maps to `p - |n|`.
+fn encode_signed<F: PrimeField>(n: i128) -> F {
+    let magnitude = n.unsigned_abs();
+    let lo = (magnitude & u128::from(u64::MAX)) as u64;
+    let hi = (magnitude >> 64) as u64;
+    let value = F::from(lo) + F::from(hi) * F::from(2u64).pow([64]);
+    if n < 0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Decode a field element as a signed integer: representatives past the
+/// field's midpoint `(p-1)/2` are negative, `value = e - p`. Magnitudes that
+/// do not fit in an `i128` saturate to `i128::MAX` / `-i128::MAX`, keeping
+/// their sign.
+fn decode_signed<F: PrimeField>(e: F) -> i128 {
+    let mut half = F::MODULUS;
+    half.divn(1);
+    let (repr, negative) = if e.into_bigint() > half {
+        ((-e).into_bigint(), true)
+    } else {
+        (e.into_bigint(), false)
+    };
+    let limbs = repr.as_ref();
+    let hi = *limbs.get(1).unwrap_or(&0);
+    let fits = hi >> 63 == 0 && limbs.iter().skip(2).all(|&l| l == 0);
+    let magnitude = if fits {
+        (limbs[0] as u128 | ((hi as u128) << 64)) as i128
+    } else {
+        i128::MAX
+    };
+    if negative {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// A signed fixed-point value, encoded as `round(x * 2^FRAC_BITS)` inside a
+/// field element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedPoint<F: PrimeField>(pub F);
+
+impl<F: PrimeField> FixedPoint<F> {
+    /// Encode a float at the fixed scale.
+    pub fn encode(value: f64) -> Self {
+        let scaled = (value * scale_i128() as f64).round() as i128;
+        Self(encode_signed(scaled))
+    }
+
+    /// Decode back to a float.
+    pub fn decode(&self) -> f64 {
+        decode_signed(self.0) as f64 / scale_i128() as f64
+    }
+
+    /// Native addition; both sides share the same scale, so no rescale is
+    /// needed.
+    pub fn add(&self, other: &Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    /// Native multiplication: the raw product carries scale `2^(2*FRAC_BITS)`,
+    /// so rescale by dividing out `2^FRAC_BITS`. Uses the same
+    /// `a * b = q * scale + r, 0 <= r < scale` floor convention as
+    /// `FixedPointVar::mul`, so a value computed off-circuit with this
+    /// method agrees bit-for-bit with one proven in-circuit.
+    pub fn mul(&self, other: &Self) -> Self {
+        let product = decode_signed(self.0) * decode_signed(other.0);
+        Self(encode_signed(product.div_euclid(scale_i128())))
+    }
+}
+
+/// In-circuit counterpart of `FixedPoint`. Allocation enforces
+/// `-2^(RANGE_BITS-1) <= n < 2^(RANGE_BITS-1)` so additions can't silently wrap around the
+/// field modulus before a caller notices.
+#[derive(Clone)]
+pub struct FixedPointVar<F: PrimeField> {
+    pub value: FpVar<F>,
+}
+
+impl<F: PrimeField> FixedPointVar<F> {
+    /// Bit width of the range check. Comfortably below the field's bit
+    /// length so the bias trick below never wraps the modulus.
+    pub const RANGE_BITS: usize = 64;
+
+    /// Enforce `-2^(RANGE_BITS-1) <= value < 2^(RANGE_BITS-1)` via the bias trick: add
+    /// `2^(RANGE_BITS-1)` so the checked quantity is non-negative, then
+    /// bit-decompose it into exactly `RANGE_BITS` bits (i.e. assert every
+    /// higher bit is zero). Shared by allocation and by every gadget that
+    /// produces a new fixed-point value (`mul`'s quotient, `add`'s sum), so
+    /// a value can never re-enter circuit state without this invariant
+    /// holding.
+    pub(crate) fn enforce_range(value: &FpVar<F>) -> Result<(), SynthesisError> {
+        let bias = F::from(1u64 << (Self::RANGE_BITS - 1));
+        let biased = value + FpVar::Constant(bias);
+        let bits = biased.to_bits_le()?;
+        for bit in &bits[Self::RANGE_BITS..] {
+            bit.enforce_equal(&Boolean::FALSE)?;
+        }
+        Ok(())
+    }
+
+    /// Allocate a fixed-point value and enforce the range invariant.
+    pub fn new_variable_checked(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<FixedPoint<F>, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let value = FpVar::new_variable(cs, || f().map(|fp| fp.0), mode)?;
+        Self::enforce_range(&value)?;
+        Ok(Self { value })
+    }
+
+    /// Addition in-circuit; no rescale is needed since both operands share
+    /// the same scale, but the sum must still be range-checked - two
+    /// in-range operands can add up to a value outside the declared range,
+    /// and without this check nothing would ever catch that.
+    pub fn add(&self, other: &Self) -> Result<Self, SynthesisError> {
+        let value = &self.value + &other.value;
+        Self::enforce_range(&value)?;
+        Ok(Self { value })
+    }
+
+    /// Multiply two fixed-point values and rescale: enforce
+    /// `a * b = q * scale + r` with `0 <= r < scale`, returning `q` after
+    /// range-checking it the same way a freshly allocated value would be -
+    /// the quotient is new circuit state, not a re-statement of an
+    /// already-checked witness, so it needs its own range check.
+    pub fn mul(&self, other: &Self) -> Result<Self, SynthesisError> {
+        let cs = self.value.cs().or(other.value.cs());
+        let product = &self.value * &other.value;
+        let scale = 1u64 << FRAC_BITS;
+
+        let (q_val, r_val) = {
+            let p = product.value().unwrap_or_default();
+            let (q, r) = rescale_witness::<F>(p, scale);
+            (q, r)
+        };
+
+        let q = FpVar::new_witness(cs.clone(), || Ok(q_val))?;
+        let r = FpVar::new_witness(cs, || Ok(r_val))?;
+
+        let scale_var = FpVar::Constant(F::from(scale));
+        (&q * &scale_var + &r).enforce_equal(&product)?;
+
+        // 0 <= r < scale: bit-decompose r into exactly log2(scale) bits.
+        // `scale` is itself a power of two (2^FRAC_BITS), so its bit length
+        // is log2(scale)+1; FRAC_BITS is the log2 we actually want here -
+        // using the bit length instead left one bit of slack in `r`,
+        // letting a prover substitute (q-1, r+scale) for the true (q, r).
+        let scale_bits = FRAC_BITS as usize;
+        let r_bits = r.to_bits_le()?;
+        for bit in &r_bits[scale_bits..] {
+            bit.enforce_equal(&Boolean::FALSE)?;
+        }
+
+        Self::enforce_range(&q)?;
+
+        Ok(Self { value: q })
+    }
+}
+
+/// Compute the witness for the `a * b = q * scale + r` rescale gadget.
+fn rescale_witness<F: PrimeField>(product: F, scale: u64) -> (F, F) {
+    let signed = decode_signed(product);
+    let scale = scale as i128;
+    let q = signed.div_euclid(scale);
+    let r = signed.rem_euclid(scale);
+    (encode_signed(q), F::from(r as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_relations::gr1cs::ConstraintSystem;
+
+    #[test]
+    fn encode_decode_roundtrip_negative() {
+        let x = FixedPoint::<Fr>::encode(-123.456);
+        assert!((x.decode() - (-123.456)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn encode_decode_roundtrip_positive() {
+        let x = FixedPoint::<Fr>::encode(42.125);
+        assert!((x.decode() - 42.125).abs() < 1e-6);
+    }
+
+    #[test]
+    fn decode_boundary_at_half_modulus() {
+        // decode_signed treats a representative strictly greater than
+        // (p-1)/2 as negative; the boundary value itself must decode as
+        // non-negative.
+        let half = Fr::from_bigint(Fr::MODULUS_MINUS_ONE_DIV_TWO).unwrap();
+        assert!(decode_signed(half) >= 0);
+
+        // One past the boundary wraps to a negative representative.
+        let just_over = half + Fr::from(1u64);
+        assert!(decode_signed(just_over) < 0);
+    }
+
+    #[test]
+    fn mul_rescale_negative_operands() {
+        let a = FixedPoint::<Fr>::encode(-2.5);
+        let b = FixedPoint::<Fr>::encode(3.0);
+        assert!((a.mul(&b).decode() - (-7.5)).abs() < 1e-6);
+
+        let c = FixedPoint::<Fr>::encode(-4.0);
+        let d = FixedPoint::<Fr>::encode(-1.5);
+        assert!((c.mul(&d).decode() - 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn new_variable_checked_accepts_in_range_witness() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let value = FixedPoint::<Fr>::encode(-1000.5);
+        FixedPointVar::new_variable_checked(cs.clone(), || Ok(value), AllocationMode::Witness)
+            .unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn mul_gadget_matches_native_floor_convention_on_inexact_product() {
+        // 1_000_000 * 2 = 2_000_000, which is not an exact multiple of
+        // scale = 2^20 = 1_048_576 (remainder 951_424, past the half-scale
+        // point) - round-to-nearest and floor disagree here (q=2 vs q=1),
+        // unlike the exact products the other `mul` tests happen to use.
+        let a = FixedPoint::<Fr>(Fr::from(1_000_000u64));
+        let b = FixedPoint::<Fr>(Fr::from(2u64));
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a_var =
+            FixedPointVar::new_variable_checked(cs.clone(), || Ok(a), AllocationMode::Witness)
+                .unwrap();
+        let b_var =
+            FixedPointVar::new_variable_checked(cs.clone(), || Ok(b), AllocationMode::Witness)
+                .unwrap();
+        let q_var = a_var.mul(&b_var).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        let expected_q = 2_000_000i128.div_euclid(scale_i128());
+        assert_eq!(decode_signed(q_var.value.value().unwrap()), expected_q);
+        assert_eq!(decode_signed(a.mul(&b).0), expected_q);
+    }
+
+    #[test]
+    fn mul_rejects_substituted_quotient_remainder_pair() {
+        // a*b = 2_000_000, true floor division by scale = 2^20 gives
+        // q=1, r=951_424. (q-1, r+scale) = (0, 2_000_000) satisfies
+        // q*scale + r == product just as well, so if the remainder range
+        // check only forced r < 2*scale instead of r < scale, this
+        // adversarial pair would slip through.
+        let a = FixedPoint::<Fr>(Fr::from(1_000_000u64));
+        let b = FixedPoint::<Fr>(Fr::from(2u64));
+        let product = 2_000_000i128;
+        let scale = scale_i128();
+        let (true_q, true_r) = rescale_witness::<Fr>(encode_signed(product), scale as u64);
+        assert_eq!(decode_signed(true_q), 1);
+        assert_eq!(true_r, Fr::from(951_424u64));
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a_var =
+            FixedPointVar::new_variable_checked(cs.clone(), || Ok(a), AllocationMode::Witness)
+                .unwrap();
+        let b_var =
+            FixedPointVar::new_variable_checked(cs.clone(), || Ok(b), AllocationMode::Witness)
+                .unwrap();
+        let product_var = &a_var.value * &b_var.value;
+
+        let bad_q = FpVar::new_witness(cs.clone(), || Ok(encode_signed::<Fr>(0))).unwrap();
+        let bad_r = FpVar::new_witness(cs.clone(), || Ok(Fr::from(2_000_000u64))).unwrap();
+        let scale_var = FpVar::Constant(Fr::from(scale as u64));
+        (&bad_q * &scale_var + &bad_r)
+            .enforce_equal(&product_var)
+            .unwrap();
+        let r_bits = bad_r.to_bits_le().unwrap();
+        for bit in &r_bits[FRAC_BITS as usize..] {
+            bit.enforce_equal(&Boolean::FALSE).unwrap();
+        }
+
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn new_variable_checked_rejects_out_of_range_witness() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        // n >= 2^(RANGE_BITS-1) must fail the bias-trick range check.
+        let out_of_range = FixedPoint(Fr::from(1u128 << (FixedPointVar::<Fr>::RANGE_BITS - 1)));
+        FixedPointVar::new_variable_checked(
+            cs.clone(),
+            || Ok(out_of_range),
+            AllocationMode::Witness,
+        )
+        .unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+}
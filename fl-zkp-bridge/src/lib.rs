@@ -1,12 +1,17 @@
 #![allow(non_snake_case)]
 #![allow(non_camel_case_types)]
 
+pub mod bench;
+pub mod commitment;
+pub mod fedavg;
+pub mod fixed_point;
+
 use ark_bn254::{Fr, G1Projective as G1};
 use ark_ff::PrimeField;
 use ark_grumpkin::Projective as G2;
 use ark_r1cs_std::fields::fp::FpVar;
 use ark_relations::gr1cs::{ConstraintSystemRef, SynthesisError};
-use ark_serialize::CanonicalSerialize;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use std::marker::PhantomData;
@@ -88,7 +93,10 @@ impl FLZKPProver {
         let poseidon_config = poseidon_canonical_config::<Fr>();
         let mut rng = ark_std::rand::rngs::OsRng;
 
-        // Preprocess ProtoGalaxy params
+        // Preprocess ProtoGalaxy params.
+        //
+        // NOT IMPLEMENTED: domain-separated non-native point absorption for
+        // this transcript - see `UPSTREAM_BLOCKED.md` (chunk0-4).
         let pg_params = PG::preprocess(&mut rng, &(poseidon_config.clone(), f_circuit))
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{:?}", e)))?;
 
@@ -127,6 +135,10 @@ impl FLZKPProver {
     }
 
     /// Prove multiple gradient updates in batch
+    ///
+    /// This folds each gradient sequentially (k=1 per round).
+    /// `prove_gradient_batch_folded` folds the same way, one ProtoGalaxy
+    /// round per gradient; neither folds a batch in a single round.
     fn prove_gradient_batch(&mut self, gradients: Vec<f64>) -> PyResult<String> {
         for (i, &gradient) in gradients.iter().enumerate() {
             self.prove_gradient_step(gradient)
@@ -134,46 +146,69 @@ impl FLZKPProver {
                     format!("Error at gradient {}: {:?}", i, e)
                 ))?;
         }
-        
-        Ok(format!("Batch of {} gradients proven. Final state: {}", 
+
+        Ok(format!("Batch of {} gradients proven. Final state: {}",
                    gradients.len(), self.current_state[0]))
     }
 
-    /// Generate final proof (returns IVC proof state)
+    /// Intended as the batched folding entry point: fold a whole batch of
+    /// gradient updates as a single multi-instance ProtoGalaxy round (k =
+    /// gradients.len()), rather than k=1 sequential folds, so a federated
+    /// round's client gradients are combined into the accumulator with one
+    /// combined polynomial and one folding challenge, instead of one
+    /// challenge per gradient.
+    ///
+    /// NOT IMPLEMENTED: needs a genuine multi-instance entry point on
+    /// `ProtoGalaxy` itself - see `UPSTREAM_BLOCKED.md` (chunk0-1). Until
+    /// that exists, this falls back to the same k=1 sequential folding as
+    /// `prove_gradient_batch`, so there's no batched F(X)/K(X) evaluation
+    /// in this crate at all for a barycentric-Lagrange rewrite to speed up
+    /// (chunk0-2, blocked on the same thing).
+    fn prove_gradient_batch_folded(&mut self, gradients: Vec<f64>) -> PyResult<String> {
+        let count = gradients.len();
+        self.prove_gradient_batch(gradients)?;
+
+        Ok(format!("Folded batch of {} gradients sequentially (single-round multi-instance folding not implemented). Current state: {}",
+                   count, self.current_state[0]))
+    }
+
+    /// Generate final proof: serializes the full `ivc_proof()` (step
+    /// count, `z_0`, `z_i`, and the running/incoming committed instances),
+    /// the same value `PG::verify` checks against, so `verify_proof` can
+    /// later reconstruct it from bytes alone instead of reaching into live
+    /// prover state.
+    ///
+    /// NOT IMPLEMENTED: this is still an IVC proof, not a compressed
+    /// constant-size SNARK — see `UPSTREAM_BLOCKED.md` for why a
+    /// ProtoGalaxy Decider is out of reach here.
     fn generate_final_proof(&self, py: Python) -> PyResult<PyObject> {
         let protogalaxy = self.protogalaxy.as_ref()
             .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("ProtoGalaxy not initialized"))?;
 
-        // For ProtoGalaxy, serialize the current IVC state
-        // This represents the proof of all folding steps
+        let ivc_proof = protogalaxy.ivc_proof();
         let mut proof_bytes = Vec::new();
-        
-        // Serialize the committed instances as proof
-        protogalaxy.U_i.serialize_compressed(&mut proof_bytes)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{:?}", e)))?;
-        
-        protogalaxy.u_i.serialize_compressed(&mut proof_bytes)
+        ivc_proof.serialize_compressed(&mut proof_bytes)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{:?}", e)))?;
 
         Ok(PyBytes::new(py, &proof_bytes).into())
     }
 
-    /// Verify the IVC proof
-    fn verify_proof(&self, _proof_bytes: Vec<u8>) -> PyResult<bool> {
-        let protogalaxy = self.protogalaxy.as_ref()
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("ProtoGalaxy not initialized"))?;
-
+    /// Verify an IVC proof produced by `generate_final_proof`: deserializes
+    /// `proof_bytes` back into the same `IVCProof` type and verifies
+    /// *that*, rather than re-verifying the live in-memory accumulator and
+    /// ignoring the bytes a caller actually passed in.
+    fn verify_proof(&self, proof_bytes: Vec<u8>) -> PyResult<bool> {
         let pg_params = self.pg_params.as_ref()
             .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("ProtoGalaxy params not initialized"))?;
 
-        // ProtoGalaxy IVC verification
         let vp = pg_params.1.clone(); // verifier params
-        
-        // Get IVC proof from current state
-        let ivc_proof = protogalaxy.ivc_proof();
-        
-        // Verify the accumulated instance
+
         type PG = ProtoGalaxy<G1, G2, AdditionFCircuit<Fr>, Pedersen<G1>, Pedersen<G2>>;
+        type IVCProof = <PG as FoldingScheme<G1, G2, AdditionFCircuit<Fr>>>::IVCProof;
+
+        let ivc_proof = IVCProof::deserialize_compressed(&proof_bytes[..])
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{:?}", e)))?;
+
         PG::verify(vp, ivc_proof)
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{:?}", e)))?;
 
@@ -193,19 +228,102 @@ impl FLZKPProver {
             Ok(0)
         }
     }
+
+    /// Checkpoint the running prover's inspectable state: the bridge's own
+    /// f64 state, the running/incoming committed instances (`U_i`, `u_i`),
+    /// and the step counter `i`.
+    ///
+    /// NOT IMPLEMENTED: full pause/resume "migrate between machines" on
+    /// another machine. That needs two things this crate can't provide on
+    /// its own: a constructor that rebuilds a live `ProtoGalaxy` (witnesses
+    /// included) from saved instances — `folding_schemes::folding::protogalaxy`
+    /// has no such `from_state`, only `init`, which always starts a fresh
+    /// accumulator — and a way to confirm the `pg_params` in scope when
+    /// resuming are the exact ones the saved commitments were produced
+    /// under (`initialize()` reseeds Pedersen/ProtoGalaxy's randomized
+    /// preprocessing every call, so two `initialize()` calls never agree).
+    /// `U_i`/`u_i` serialize via `CanonicalSerialize` the same way
+    /// `generate_final_proof` already relies on; the full `ProtoGalaxy`
+    /// struct (witnesses included) is not confirmed to implement it.
+    fn save_state(&self, py: Python) -> PyResult<PyObject> {
+        let protogalaxy = self.protogalaxy.as_ref()
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("ProtoGalaxy not initialized"))?;
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(self.current_state.len() as u64).to_le_bytes());
+        for v in &self.current_state {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        bytes.extend_from_slice(&protogalaxy.i.into_bigint().as_ref()[0].to_le_bytes());
+        protogalaxy.U_i.serialize_compressed(&mut bytes)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{:?}", e)))?;
+        protogalaxy.u_i.serialize_compressed(&mut bytes)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("{:?}", e)))?;
+
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
+    /// Read back the bridge's own f64 state and step counter from bytes
+    /// produced by `save_state`.
+    ///
+    /// NOT IMPLEMENTED: this does not resume proving. See `save_state` for
+    /// why a live `ProtoGalaxy` accumulator can't be rebuilt from saved
+    /// instances in this crate; callers must call `initialize()` and start
+    /// a fresh fold instead of expecting `prove_gradient_step` to work
+    /// after this.
+    fn load_state(&mut self, state_bytes: Vec<u8>) -> PyResult<String> {
+        if state_bytes.len() < 8 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("state bytes too short"));
+        }
+        let mut len_buf = [0u8; 8];
+        len_buf.copy_from_slice(&state_bytes[0..8]);
+        let n = u64::from_le_bytes(len_buf) as usize;
+
+        // n comes straight from the input bytes, so a malicious/corrupt
+        // checkpoint can pick it so that `8 + n * 8` wraps `usize` back to
+        // a small value - that would let the length check below pass
+        // against a short buffer, then hit Vec::with_capacity(n)'s
+        // capacity-overflow panic. checked_mul/checked_add turn that into
+        // the same "truncated" error instead of a panic.
+        let header_len = 8usize
+            .checked_add(n.checked_mul(8).ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>("state bytes truncated")
+            })?)
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>("state bytes truncated")
+            })?;
+        let total_len = header_len.checked_add(8).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>("state bytes truncated")
+        })?;
+        if state_bytes.len() < total_len {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("state bytes truncated"));
+        }
+
+        let mut current_state = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&state_bytes[8 + i * 8..8 + (i + 1) * 8]);
+            current_state.push(f64::from_le_bytes(buf));
+        }
+
+        let mut step_buf = [0u8; 8];
+        step_buf.copy_from_slice(&state_bytes[header_len..header_len + 8]);
+        let step = u64::from_le_bytes(step_buf);
+
+        self.current_state = current_state;
+
+        Ok(format!(
+            "Read checkpoint at step {} (resume not implemented; call initialize() to start a fresh fold)",
+            step
+        ))
+    }
 }
 
-/// Helper function to convert f64 to field element
-/// For production, you'd want a more sophisticated encoding
+/// Convert a float to a field element via the fixed-point encoding in
+/// [`fixed_point`], rather than truncating and hoping it doesn't decode
+/// negative or overflow the scale.
 fn float_to_field(value: f64) -> Fr {
-    // Scale and convert to integer representation
-    // This is a simple approach - for production, use fixed-point arithmetic
-    let scaled = (value * 1_000_000.0) as i64;
-    if scaled >= 0 {
-        Fr::from(scaled as u64)
-    } else {
-        -Fr::from((-scaled) as u64)
-    }
+    fixed_point::FixedPoint::<Fr>::encode(value).0
 }
 
 /// Python module definition